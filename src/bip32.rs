@@ -1,5 +1,6 @@
 use crate::child_number::ChildNumber;
-use crate::keys::KeyFingerprint;
+use crate::error;
+use crate::keys::{ExtendedPubKey, KeyFingerprint};
 use crate::{
     keys::ExtendedKey,
     path::Path,
@@ -11,6 +12,7 @@ use num_bigint::{BigInt, Sign};
 use ripemd160::{Digest, Ripemd160};
 use sha2::Sha256;
 use std::str::FromStr;
+use zeroize::Zeroize;
 
 const MASTER_PATH: &str = "0'";
 const KEY_SIZE: usize = 32;
@@ -37,16 +39,20 @@ impl Bip32 {
         let seed = hex::decode(seed.into()).unwrap();
         let mut hmac = HmacSha512::new_from_slice(BIP39_DOMAIN_SEPARATOR.as_bytes()).unwrap();
         hmac.update(seed.as_ref());
-        let result = hmac.finalize().into_bytes();
+        let mut result = hmac.finalize().into_bytes();
         let (secret_key, chain_code) = result.split_at(KEY_SIZE);
         let master_path = Path::from_str(MASTER_PATH)?;
 
-        Ok(ExtendedKey {
+        let key = ExtendedKey {
             private_key: secret_key.try_into().unwrap(),
             chain_code: chain_code.try_into().unwrap(),
             path: master_path,
             finger_print: KeyFingerprint::default(),
-        })
+        };
+        // Scrub IL (master secret) and IR from the HMAC output buffer.
+        result.as_mut_slice().zeroize();
+
+        Ok(key)
     }
 
     pub fn derive(key: ExtendedKey, child_number: ChildNumber) -> ExtendedKey {
@@ -67,7 +73,7 @@ impl Bip32 {
         }
 
         hmac.update(&index.to_be_bytes());
-        let result = hmac.finalize().into_bytes();
+        let mut result = hmac.finalize().into_bytes();
 
         let (secret_key, chain_code) = result.split_at(KEY_SIZE);
 
@@ -76,7 +82,9 @@ impl Bip32 {
         // ki = parse256(IL) + kpar (mod n)
         let parse256 = BigInt::from_bytes_be(Sign::Plus, secret_key);
         let k_par = BigInt::from_bytes_be(Sign::Plus, &key.private_key);
-        let ki = Point::modulo(parse256 + k_par, Some(curve.r)).to_bytes_be();
+        // ser256(ki) left-pads to 32 bytes so a child scalar with high zero bytes is
+        // not silently truncated.
+        let ki = Point::ser256(&Point::modulo(parse256 + k_par, Some(curve.r)));
 
         let mut path = key.path.clone();
         path.depth += 1;
@@ -86,12 +94,78 @@ impl Bip32 {
         // The first 32 bits of the identifier are called the key fingerprint.
         let finger_print = Ripemd160::digest(&Sha256::digest(&m_public_key)).to_vec();
 
-        ExtendedKey {
-            private_key: ki.1.as_slice()[..32].try_into().unwrap(),
+        let child = ExtendedKey {
+            private_key: ki,
             chain_code: chain_code.try_into().unwrap(),
             path,
             finger_print: finger_print.as_slice()[..4].try_into().unwrap(),
+        };
+        // Scrub IL (the child secret addend) and IR from the HMAC output buffer.
+        result.as_mut_slice().zeroize();
+
+        child
+    }
+
+    /*
+    Walk an entire BIP44-style path in one call, folding `derive` over every child
+    number in `path`. The leading master marker `m` carries no derivation step and is
+    dropped by `Path::from_str`, so the fold starts at the first real level. Every other
+    element, including a leading `0'`, is derived as a level of its own.
+     */
+    pub fn derive_path(master: ExtendedKey, path: &Path) -> ExtendedKey {
+        path.child_numbers
+            .iter()
+            .fold(master, |key, cn| Bip32::derive(key, cn.clone()))
+    }
+
+    /*
+    Convenience wrapper accepting the path as a string like `m/44'/0'/0'/0/0`.
+     */
+    pub fn derive_path_str(master: ExtendedKey, path: &str) -> Result<ExtendedKey> {
+        let path = Path::from_str(path)?;
+
+        Ok(Self::derive_path(master, &path))
+    }
+
+    /*
+    CKDpub: derive a child public key from a parent public key (watch-only).
+    Compute I = HMAC-SHA512(Key = c_par, Data = serP(Kpar) || ser32(i)), split into
+    IL and IR, and set Ki = point(parse256(IL)) + Kpar with child chain code IR.
+    Hardened children have no public derivation and return an error.
+     */
+    pub fn derive_pub(
+        key: ExtendedPubKey,
+        child_number: ChildNumber,
+    ) -> Result<ExtendedPubKey> {
+        if child_number.is_hardened {
+            return Err(error::Error::HardenedPublicDerivation {
+                index: child_number.index,
+            });
         }
+
+        let m_public_key = key.public_key.serialize_compressed();
+        let mut hmac = HmacSha512::new_from_slice(key.chain_code.as_ref()).unwrap();
+        hmac.update(&m_public_key);
+        hmac.update(&child_number.index.to_be_bytes());
+        let result = hmac.finalize().into_bytes();
+
+        let (secret_key, chain_code) = result.split_at(KEY_SIZE);
+
+        // Ki = point(parse256(IL)) + Kpar
+        let parse256 = BigInt::from_bytes_be(Sign::Plus, secret_key);
+        let il_point = Point::double_and_add(Point::secp256k1_base_point(), parse256);
+        let public_key = il_point.add(key.public_key);
+
+        // The parent key's identifier is the Hash160 of its serialized public key K.
+        let finger_print = Ripemd160::digest(&Sha256::digest(&m_public_key)).to_vec();
+
+        Ok(ExtendedPubKey {
+            public_key,
+            chain_code: chain_code.try_into().unwrap(),
+            depth: key.depth + 1,
+            parent_finger_print: finger_print.as_slice()[..4].try_into().unwrap(),
+            child_number,
+        })
     }
 }
 
@@ -158,4 +232,52 @@ mod tests {
         assert_eq!(target.attrs().chain_code, next.chain_code);
         assert_eq!(target.to_extended_key(Prefix::XPRV).to_string(), next.to_base58(Version::Private))
     }
+
+    #[test]
+    fn derive_pub_equals_neutered_priv() {
+        let seed = "5d6c43a28c7177a25c2b6812dec03d9ca5b1f5988b276f9504fd69f8e32f0797cacda47c9746f8c97a273a525de465e67b65b17d75bacdbc0d01e788b9646288";
+
+        // CKDpub on the watch-only parent.
+        let parent_pub = Bip32::from_seed(seed).unwrap().neuter();
+        let child_pub = Bip32::derive_pub(parent_pub, ChildNumber::from_str("0").unwrap()).unwrap();
+
+        // CKDpriv then neuter must land on the same public child.
+        let child_priv = Bip32::derive(
+            Bip32::from_seed(seed).unwrap(),
+            ChildNumber::from_str("0").unwrap(),
+        )
+        .neuter();
+
+        assert_eq!(
+            child_pub.to_base58(Version::Public),
+            child_priv.to_base58(Version::Public)
+        );
+    }
+
+    #[test]
+    fn derive_path_matches_chained_derive() {
+        let seed = "5d6c43a28c7177a25c2b6812dec03d9ca5b1f5988b276f9504fd69f8e32f0797cacda47c9746f8c97a273a525de465e67b65b17d75bacdbc0d01e788b9646288";
+
+        // Walking the whole path in one call must equal stepping it level by level.
+        let one_shot = Bip32::derive_path_str(Bip32::from_seed(seed).unwrap(), "m/44'/0'/0'/0/0").unwrap();
+        let stepped = ["44'", "0'", "0'", "0", "0"].iter().fold(
+            Bip32::from_seed(seed).unwrap(),
+            |key, level| Bip32::derive(key, ChildNumber::from_str(level).unwrap()),
+        );
+
+        assert_eq!(one_shot.chain_code, stepped.chain_code);
+        assert_eq!(
+            one_shot.to_base58(Version::Private),
+            stepped.to_base58(Version::Private)
+        );
+    }
+
+    #[test]
+    fn derive_pub_rejects_hardened() {
+        let seed = "5d6c43a28c7177a25c2b6812dec03d9ca5b1f5988b276f9504fd69f8e32f0797cacda47c9746f8c97a273a525de465e67b65b17d75bacdbc0d01e788b9646288";
+        let parent_pub = Bip32::from_seed(seed).unwrap().neuter();
+        let hardened = ChildNumber::from_str("0'").unwrap();
+
+        assert!(Bip32::derive_pub(parent_pub, hardened).is_err());
+    }
 }
@@ -1,12 +1,27 @@
 use crate::error;
 use std::str::FromStr;
 
+const HARDENED_FLAG: u32 = 1 << 31;
+
 #[derive(Clone)]
 pub struct ChildNumber {
     pub is_hardened: bool,
     pub index: u32,
 }
 
+impl ChildNumber {
+    // ser32(i): the 4-byte big-endian child number, with the high bit set for hardened
+    // children so the serialized value is `index | 0x80000000`.
+    pub fn to_be_bytes(&self) -> [u8; 4] {
+        let index = match self.is_hardened {
+            true => self.index | HARDENED_FLAG,
+            false => self.index,
+        };
+
+        index.to_be_bytes()
+    }
+}
+
 impl FromStr for ChildNumber {
     type Err = error::Error;
 
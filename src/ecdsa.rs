@@ -0,0 +1,185 @@
+use crate::{
+    secp256k1::{Curve, Point},
+    HmacSha256,
+};
+use hmac::{Mac, NewMac};
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+
+/*
+ECDSA signing and verification over the secp256k1 `Point` arithmetic.
+
+Nonces are derived deterministically per RFC 6979 so that a given (private key, message
+hash) pair always yields the same signature without needing an RNG. The DRBG is the
+HMAC-SHA256 construction mandated by the RFC (SHA-512 is used elsewhere for BIP32 but is
+not the right primitive here). Signatures are low-`s` normalized as the rust-bitcoin
+signing paths do.
+
+Reference: https://datatracker.ietf.org/doc/html/rfc6979
+ */
+pub fn sign(message_hash: &[u8; 32], private_key: &[u8; 32]) -> (BigInt, BigInt) {
+    let curve = Curve::secp256k1();
+    let n = curve.r;
+    let d = BigInt::from_bytes_be(Sign::Plus, private_key);
+    let z = BigInt::from_bytes_be(Sign::Plus, message_hash);
+
+    let mut drbg = Rfc6979::new(&d, &z, &n);
+    loop {
+        let k = drbg.generate(&n);
+        // R = k·G, r = R.x mod n
+        let point = Point::double_and_add(Point::secp256k1_base_point(), k.clone());
+        let r = Point::modulo(point.x, Some(n.clone()));
+        if r == BigInt::zero() {
+            continue;
+        }
+
+        // s = k⁻¹ (z + r·d) mod n
+        let k_inv = Point::invert(k, Some(n.clone()));
+        let mut s = Point::modulo(k_inv * (&z + &r * &d), Some(n.clone()));
+        if s == BigInt::zero() {
+            continue;
+        }
+
+        // Low-s normalization: enforce s ≤ n/2 to keep signatures canonical.
+        if s > &n / BigInt::from(2) {
+            s = &n - &s;
+        }
+
+        return (r, s);
+    }
+}
+
+pub fn verify(message_hash: &[u8; 32], signature: (BigInt, BigInt), public_point: &Point) -> bool {
+    let curve = Curve::secp256k1();
+    let n = curve.r;
+    let (r, s) = signature;
+    if r <= BigInt::zero() || r >= n || s <= BigInt::zero() || s >= n {
+        return false;
+    }
+
+    let z = BigInt::from_bytes_be(Sign::Plus, message_hash);
+    let s_inv = Point::invert(s, Some(n.clone()));
+    let u1 = Point::modulo(&z * &s_inv, Some(n.clone()));
+    let u2 = Point::modulo(&r * &s_inv, Some(n.clone()));
+
+    // (u1·G + u2·Q).x mod n == r
+    let point = Point::double_and_add(Point::secp256k1_base_point(), u1)
+        .add(Point::double_and_add(public_point.clone(), u2));
+    if point.x == BigInt::zero() && point.y == BigInt::zero() {
+        return false;
+    }
+
+    Point::modulo(point.x, Some(n)) == r
+}
+
+/*
+The RFC 6979 HMAC_DRBG used to derive the nonce `k`. It keeps the running `K`/`V` state
+so that, on a rejected candidate, the caller can ask for the next one.
+ */
+struct Rfc6979 {
+    k: Vec<u8>,
+    v: Vec<u8>,
+}
+
+impl Rfc6979 {
+    fn new(d: &BigInt, z: &BigInt, n: &BigInt) -> Self {
+        // int2octets(d) is the 32-byte big-endian private scalar. bits2octets(h1) is
+        // int2octets(bits2int(h1) mod q), i.e. the hash reduced mod n — not the raw
+        // hash — so the seed matches the RFC 6979 test vectors.
+        let int2octets = Point::ser256(d);
+        let bits2octets = Point::ser256(&Point::modulo(z.clone(), Some(n.clone())));
+        let mut v = vec![0x01u8; 32];
+        let mut k = vec![0x00u8; 32];
+
+        k = Self::hmac(&k, &[&v[..], &[0x00u8], &int2octets[..], &bits2octets[..]].concat());
+        v = Self::hmac(&k, &v);
+        k = Self::hmac(&k, &[&v[..], &[0x01u8], &int2octets[..], &bits2octets[..]].concat());
+        v = Self::hmac(&k, &v);
+
+        Self { k, v }
+    }
+
+    fn generate(&mut self, n: &BigInt) -> BigInt {
+        loop {
+            // One HMAC-SHA256 block already supplies the full 256-bit candidate.
+            self.v = Self::hmac(&self.k, &self.v);
+            let candidate = BigInt::from_bytes_be(Sign::Plus, &self.v);
+            if candidate >= BigInt::from(1) && &candidate < n {
+                return candidate;
+            }
+            self.k = Self::hmac(&self.k, &[&self.v[..], &[0x00]].concat());
+            self.v = Self::hmac(&self.k, &self.v);
+        }
+    }
+
+    fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut hmac = HmacSha256::new_from_slice(key).unwrap();
+        hmac.update(data);
+
+        hmac.finalize().into_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip32::Bip32;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+    use k256::ecdsa::{Signature, SigningKey};
+
+    const SEED: &str = "5d6c43a28c7177a25c2b6812dec03d9ca5b1f5988b276f9504fd69f8e32f0797cacda47c9746f8c97a273a525de465e67b65b17d75bacdbc0d01e788b9646288";
+
+    fn private_key() -> [u8; 32] {
+        Bip32::from_seed(SEED).unwrap().private_key
+    }
+
+    fn public_point(private_key: &[u8; 32]) -> Point {
+        let d = BigInt::from_bytes_be(Sign::Plus, private_key);
+        Point::double_and_add(Point::secp256k1_base_point(), d)
+    }
+
+    #[test]
+    fn sign_then_verify_round_trip() {
+        let private_key = private_key();
+        let hash = [0x42u8; 32];
+        let signature = sign(&hash, &private_key);
+
+        assert!(verify(&hash, signature, &public_point(&private_key)));
+    }
+
+    #[test]
+    fn verify_rejects_tampered_message() {
+        let private_key = private_key();
+        let hash = [0x42u8; 32];
+        let signature = sign(&hash, &private_key);
+
+        let mut tampered = hash;
+        tampered[0] ^= 0x01;
+        assert!(!verify(&tampered, signature, &public_point(&private_key)));
+    }
+
+    #[test]
+    fn rfc6979_nonces_are_deterministic() {
+        let private_key = private_key();
+        let hash = [0x42u8; 32];
+
+        assert_eq!(sign(&hash, &private_key), sign(&hash, &private_key));
+    }
+
+    #[test]
+    fn matches_k256_rfc6979_signature() {
+        let private_key = private_key();
+        let hash = [0x42u8; 32];
+
+        // Oracle: k256's RFC 6979 + low-s signer must produce byte-identical (r, s).
+        let signing_key = SigningKey::from_slice(&private_key).unwrap();
+        let oracle: Signature = signing_key.sign_prehash(&hash).unwrap();
+
+        let (r, s) = sign(&hash, &private_key);
+        let mut ours = Vec::with_capacity(64);
+        ours.extend_from_slice(&Point::ser256(&r));
+        ours.extend_from_slice(&Point::ser256(&s));
+
+        assert_eq!(oracle.to_bytes().as_slice(), ours.as_slice());
+    }
+}
@@ -12,4 +12,12 @@ pub enum Error {
     PathFromStr { path: String },
     #[snafu(display("Cannot use FromStr with given string, {}", child_number))]
     ChildNumberFromStr { child_number: String },
+    #[snafu(display("Cannot derive a hardened child ({}) from a public key", index))]
+    HardenedPublicDerivation { index: u32 },
+    #[snafu(display("Base58 checksum does not match the payload"))]
+    Base58Checksum,
+    #[snafu(display("Extended key payload has an unexpected length: {}", length))]
+    ExtendedKeyLength { length: usize },
+    #[snafu(display("Unknown or unsupported extended key version: {:08x}", version))]
+    UnknownVersion { version: u32 },
 }
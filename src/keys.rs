@@ -1,11 +1,68 @@
-use crate::{path::Path, secp256k1::Point, version::Version};
+use crate::{
+    child_number::ChildNumber, error, path::Path, secp256k1::Point, version::Version, Result,
+};
 use num_bigint::{BigInt, Sign};
-use num_traits::Zero;
+use std::str::FromStr;
+use zeroize::Zeroize;
 
 pub type KeyFingerprint = [u8; 4];
 pub type PrivateKey = [u8; 32];
 pub type ChainCode = [u8; 32];
 
+const HARDENED_FLAG: u32 = 1 << 31;
+// version(4) + depth(1) + fingerprint(4) + child number(4) + chain code(32) + key(33)
+const EXTENDED_KEY_SIZE: usize = 78;
+
+/*
+The version-agnostic fields shared by an xprv and an xpub, recovered from the decoded
+78-byte base58check payload. `body` is the trailing 33 bytes: a `0x00`-padded private
+key for private versions or a SEC1 compressed point for public versions.
+ */
+struct ExtendedKeyParts {
+    depth: usize,
+    finger_print: KeyFingerprint,
+    child_number: ChildNumber,
+    chain_code: ChainCode,
+    body: [u8; 33],
+}
+
+/*
+base58check-decode an extended key string, verify the double-SHA256 checksum, match
+the leading 4 bytes against the `Version` enum, and split out the common fields.
+ */
+fn decode_base58(s: &str) -> Result<(Version, ExtendedKeyParts)> {
+    let data = bs58::decode(s)
+        .with_check(None)
+        .into_vec()
+        .map_err(|_| error::Error::Base58Checksum)?;
+
+    if data.len() != EXTENDED_KEY_SIZE {
+        return Err(error::Error::ExtendedKeyLength { length: data.len() });
+    }
+
+    let version = Version::from_be_bytes(data[0..4].try_into().unwrap())?;
+    let depth = data[4] as usize;
+    let finger_print: KeyFingerprint = data[5..9].try_into().unwrap();
+    let raw_child = u32::from_be_bytes(data[9..13].try_into().unwrap());
+    let child_number = ChildNumber {
+        is_hardened: raw_child & HARDENED_FLAG != 0,
+        index: raw_child & !HARDENED_FLAG,
+    };
+    let chain_code: ChainCode = data[13..45].try_into().unwrap();
+    let body: [u8; 33] = data[45..78].try_into().unwrap();
+
+    Ok((
+        version,
+        ExtendedKeyParts {
+            depth,
+            finger_print,
+            child_number,
+            chain_code,
+            body,
+        },
+    ))
+}
+
 pub struct ExtendedKey {
     pub(crate) private_key: PrivateKey,
     pub(crate) chain_code: ChainCode,
@@ -13,6 +70,15 @@ pub struct ExtendedKey {
     pub(crate) finger_print: KeyFingerprint,
 }
 
+// Scrub the secret scalar and chain code from memory when the key goes out of scope so
+// they do not linger on the heap/stack. The path and fingerprint are public metadata.
+impl Drop for ExtendedKey {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
 impl ExtendedKey {
     pub fn public_key(&self, is_compressed: bool) -> String {
         let key = BigInt::from_bytes_be(Sign::Plus, &self.private_key);
@@ -20,16 +86,17 @@ impl ExtendedKey {
         let point = Point::double_and_add(Point::secp256k1_base_point(), key);
 
         match is_compressed {
-            true => {
-                // serializes the coordinate pair P = (x,y) as a byte sequence using SEC1's compressed form: (0x02 or 0x03) || ser256(x), where the header byte depends on the parity of the omitted y coordinate.
-                let prefix = match &point.y & BigInt::from(1) != BigInt::zero() {
-                    true => "03",
-                    false => "02",
-                };
-
-                format!("{}{}", prefix, point.x.to_str_radix(16))
+            // SEC1 compressed form: (0x02 or 0x03) || ser256(x). `x`/`y` are left-padded
+            // to exactly 32 bytes so leading zero bytes never shorten the key.
+            true => hex::encode(point.serialize_compressed()),
+            false => {
+                let mut data = Vec::with_capacity(65);
+                data.push(0x04);
+                data.extend_from_slice(&Point::ser256(&point.x));
+                data.extend_from_slice(&Point::ser256(&point.y));
+
+                hex::encode(data)
             }
-            false => format!("04{}{}", point.x.to_str_radix(16), point.y.to_str_radix(16)),
         }
     }
 
@@ -48,7 +115,7 @@ impl ExtendedKey {
 
         // child number
         let child_number = self.path.child_numbers.last().unwrap();
-        let child_number: [u8; 4] = child_number.index.to_be_bytes();
+        let child_number: [u8; 4] = child_number.to_be_bytes();
         data.append(&mut child_number.to_vec());
 
         let chain_code = self.chain_code;
@@ -61,12 +128,201 @@ impl ExtendedKey {
                 data.append(&mut private_key.to_vec());
             }
             Version::Public | Version::TestnetPublic => {
-                let public_key = self.public_key(true);
-                let public_key = &public_key.as_bytes()[..33];
-                data.append(&mut public_key.to_vec());
+                let key = BigInt::from_bytes_be(Sign::Plus, &self.private_key);
+                let point = Point::double_and_add(Point::secp256k1_base_point(), key);
+                data.append(&mut point.serialize_compressed());
             }
         };
 
         bs58::encode(data).with_check().into_string()
     }
+
+    /*
+    Import an extended private key (xprv) from its base58check string, the inverse of
+    `to_base58(Version::Private)`. The trailing key body is `0x00 || ser256(k)`, so the
+    pad byte is stripped off. Public versions belong to `ExtendedPubKey::from_base58`.
+     */
+    pub fn from_base58(s: &str) -> Result<Self> {
+        let (version, parts) = decode_base58(s)?;
+        match version {
+            Version::Private | Version::TestnetPrivate => {}
+            version => {
+                return Err(error::Error::UnknownVersion {
+                    version: u32::from_be_bytes(version.to_be_bytes()),
+                })
+            }
+        }
+
+        Ok(Self {
+            private_key: parts.body[1..33].try_into().unwrap(),
+            chain_code: parts.chain_code,
+            path: Path {
+                depth: parts.depth,
+                child_numbers: vec![parts.child_number],
+            },
+            finger_print: parts.finger_print,
+        })
+    }
+
+    /*
+    Neuter an extended private key into a watch-only extended public key: compute the
+    public point point(kpar) and keep the chain code, depth, parent fingerprint and
+    child number so that CKDpub can walk the non-hardened branch without any secret.
+     */
+    pub fn neuter(&self) -> ExtendedPubKey {
+        let key = BigInt::from_bytes_be(Sign::Plus, &self.private_key);
+        let public_key = Point::double_and_add(Point::secp256k1_base_point(), key);
+        let child_number = self.path.child_numbers.last().unwrap().clone();
+
+        ExtendedPubKey {
+            public_key,
+            chain_code: self.chain_code,
+            depth: self.path.depth,
+            parent_finger_print: self.finger_print,
+            child_number,
+        }
+    }
+}
+
+/*
+A neutered extended key holding only the public point, with no access to any secret
+material. It mirrors the `ExtendedPubKey`/`ckd_pub` split of the rust-bitcoin key
+types and can only walk the non-hardened (CKDpub) branch.
+ */
+pub struct ExtendedPubKey {
+    pub(crate) public_key: Point,
+    pub(crate) chain_code: ChainCode,
+    pub(crate) depth: usize,
+    pub(crate) parent_finger_print: KeyFingerprint,
+    pub(crate) child_number: ChildNumber,
+}
+
+impl ExtendedPubKey {
+    pub fn to_base58(&self, version: Version) -> String {
+        let mut data: Vec<u8> = Vec::new();
+        // version
+        let version_data: [u8; 4] = version.to_be_bytes();
+        data.append(&mut version_data.to_vec());
+
+        // derive
+        data.push(self.depth as u8);
+
+        // fingerprint
+        let finger_print = self.parent_finger_print;
+        data.append(&mut finger_print.to_vec());
+
+        // child number
+        let child_number: [u8; 4] = self.child_number.to_be_bytes();
+        data.append(&mut child_number.to_vec());
+
+        let chain_code = self.chain_code;
+        data.append(&mut chain_code.to_vec());
+
+        // serP(K): the 33-byte SEC1 compressed public key.
+        data.append(&mut self.public_key.serialize_compressed());
+
+        bs58::encode(data).with_check().into_string()
+    }
+
+    /*
+    Import an extended public key (xpub) from its base58check string. The trailing key
+    body is a 33-byte SEC1 compressed point, which is decompressed back into a `Point`.
+     */
+    pub fn from_base58(s: &str) -> Result<Self> {
+        let (version, parts) = decode_base58(s)?;
+        match version {
+            Version::Public | Version::TestnetPublic => {}
+            version => {
+                return Err(error::Error::UnknownVersion {
+                    version: u32::from_be_bytes(version.to_be_bytes()),
+                })
+            }
+        }
+
+        Ok(Self {
+            public_key: Point::decompress(&parts.body),
+            chain_code: parts.chain_code,
+            depth: parts.depth,
+            parent_finger_print: parts.finger_print,
+            child_number: parts.child_number,
+        })
+    }
+}
+
+impl FromStr for ExtendedKey {
+    type Err = error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ExtendedKey::from_base58(s)
+    }
+}
+
+impl FromStr for ExtendedPubKey {
+    type Err = error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ExtendedPubKey::from_base58(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip32::Bip32;
+
+    const SEED: &str = "5d6c43a28c7177a25c2b6812dec03d9ca5b1f5988b276f9504fd69f8e32f0797cacda47c9746f8c97a273a525de465e67b65b17d75bacdbc0d01e788b9646288";
+
+    #[test]
+    fn xprv_round_trips_through_base58() {
+        let xprv = Bip32::from_seed(SEED).unwrap().to_base58(Version::Private);
+        let decoded = ExtendedKey::from_base58(&xprv).unwrap();
+
+        assert_eq!(decoded.to_base58(Version::Private), xprv);
+    }
+
+    #[test]
+    fn xprv_round_trips_at_hardened_level() {
+        // A hardened child must re-encode its `0x80000000` flag, so the round-trip has
+        // to survive a hardened level, not just the master key.
+        let master = Bip32::from_seed(SEED).unwrap();
+        let child = Bip32::derive(master, ChildNumber::from_str("0'").unwrap());
+        let xprv = child.to_base58(Version::Private);
+
+        let decoded = ExtendedKey::from_base58(&xprv).unwrap();
+        assert_eq!(decoded.to_base58(Version::Private), xprv);
+    }
+
+    #[test]
+    fn xpub_round_trips_through_base58() {
+        let xpub = Bip32::from_seed(SEED).unwrap().to_base58(Version::Public);
+        let decoded = ExtendedPubKey::from_base58(&xpub).unwrap();
+
+        assert_eq!(decoded.to_base58(Version::Public), xpub);
+    }
+
+    #[test]
+    fn from_base58_rejects_corrupted_checksum() {
+        let mut xprv = Bip32::from_seed(SEED).unwrap().to_base58(Version::Private);
+        // Flip the final character so the double-SHA256 checksum no longer matches.
+        let last = xprv.pop().unwrap();
+        xprv.push(if last == 'a' { 'b' } else { 'a' });
+
+        assert!(ExtendedKey::from_base58(&xprv).is_err());
+    }
+
+    #[test]
+    fn sec1_serialization_is_fixed_width() {
+        // A coordinate with high zero bytes must still be left-padded to 32 bytes so
+        // the compressed key stays 33 bytes instead of being silently shortened.
+        let low_x = Point::new(BigInt::from(1), BigInt::from(2));
+        let compressed = low_x.serialize_compressed();
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(&compressed[1..32], &[0u8; 31]);
+        assert_eq!(compressed[32], 1);
+
+        // Real keys serialize to the canonical compressed / uncompressed hex widths.
+        let key = Bip32::from_seed(SEED).unwrap();
+        assert_eq!(key.public_key(true).len(), 66);
+        assert_eq!(key.public_key(false).len(), 130);
+    }
 }
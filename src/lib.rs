@@ -1,6 +1,7 @@
 pub mod bip32;
 pub mod bip39;
 pub mod child_number;
+pub mod ecdsa;
 pub mod error;
 pub mod keys;
 pub mod path;
@@ -9,3 +10,4 @@ pub mod version;
 
 pub type Result<T, E = error::Error> = std::result::Result<T, E>;
 pub type HmacSha512 = hmac::Hmac<sha2::Sha512>;
+pub type HmacSha256 = hmac::Hmac<sha2::Sha256>;
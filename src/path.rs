@@ -11,7 +11,15 @@ impl FromStr for Path {
     type Err = error::Error;
 
     fn from_str(path: &str) -> Result<Self, Self::Err> {
-        let chunks = path.split('/').collect::<Vec<&str>>();
+        // A BIP44-style path may start with the master marker `m`, e.g. `m/44'/0'/0'`.
+        // It carries no index, so drop it before parsing the individual levels.
+        let chunks = path
+            .split('/')
+            .filter(|&s| s != "m" && s != "M")
+            .collect::<Vec<&str>>();
+        if chunks.is_empty() {
+            return Err(error::Error::PathFromStr { path: path.to_string() });
+        }
         let depth = chunks.len() - 1;
 
         let child_numbers = chunks
@@ -1,5 +1,5 @@
 use num_bigint::{BigInt, Sign};
-use num_traits::{One, Zero};
+use num_traits::{One, ToPrimitive, Zero};
 
 /*
 secp256k1 base point in affine coordinates:
@@ -68,6 +68,54 @@ impl Point {
         Self::new(x, y)
     }
 
+    /*
+    serP(P): serializes the coordinate pair P = (x,y) as a byte sequence using SEC1's
+    compressed form: (0x02 or 0x03) || ser256(x), where the header byte encodes the
+    parity of the omitted y coordinate. ser256(x) is always left-padded to 32 bytes.
+     */
+    pub fn serialize_compressed(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(33);
+        data.push(match &self.y & BigInt::one() != BigInt::zero() {
+            true => 0x03,
+            false => 0x02,
+        });
+        data.extend_from_slice(&Self::ser256(&self.x));
+
+        data
+    }
+
+    /*
+    ser256(p): serializes the integer p as a 32-byte big-endian sequence, left-padding
+    with zero bytes so that leading zeros in p are preserved.
+     */
+    pub fn ser256(number: &BigInt) -> [u8; 32] {
+        let (_, bytes) = number.to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - bytes.len()..].copy_from_slice(&bytes);
+
+        out
+    }
+
+    /*
+    Recover the full coordinate pair from a 33-byte SEC1 compressed public key.
+    secp256k1 has p ≡ 3 (mod 4), so the square root of y² = x³ + 7 (mod p) is just
+    (x³ + 7)^((p+1)/4) (mod p); the 0x02/0x03 header fixes the parity of y.
+     */
+    pub fn decompress(bytes: &[u8]) -> Self {
+        let curve = Curve::secp256k1();
+        let x = BigInt::from_bytes_be(Sign::Plus, &bytes[1..33]);
+        let alpha = Self::modulo(&x * &x * &x + &curve.b, Some(curve.p.clone()));
+        let exponent = (&curve.p + BigInt::one()) / BigInt::from(4);
+        let mut y = alpha.modpow(&exponent, &curve.p);
+        // The header byte encodes the parity of y; flip to the other root on mismatch.
+        let want_odd = bytes[0] & 1 == 1;
+        if (&y & BigInt::one() != BigInt::zero()) != want_odd {
+            y = &curve.p - &y;
+        }
+
+        Self::new(x, y)
+    }
+
     pub fn secp256k1_base_point() -> Self {
         let x = BigInt::from_bytes_be(Sign::Plus, &G_X);
         let y = BigInt::from_bytes_be(Sign::Plus, &G_Y);
@@ -75,19 +123,46 @@ impl Point {
         Self::new(x, y)
     }
 
+    /*
+    Scalar multiplication n·d over secp256k1.
+
+    The affine `add`/`double` each perform an extended-Euclid `invert`, so an O(bits)
+    double-and-add dominates key generation with big-integer inversions. Instead we run
+    the ladder in Jacobian coordinates (X, Y, Z) with affine x = X/Z² and y = Y/Z³ mod
+    p, where additions and doublings need no inversion, and convert back to affine with
+    a single `invert` at the very end. A 4-bit window over the scalar cuts the number of
+    additions by pre-computing the sixteen multiples [0..16)·d once.
+
+    Reference: https://paulmillr.com/posts/noble-secp256k1-fast-ecc/
+     */
     pub fn double_and_add(d: Point, n: BigInt) -> Self {
-        let mut p = Point::zero();
-        let mut n = n;
-        let mut d = d;
-        while n > BigInt::zero() {
-            if n.clone() & BigInt::one() != BigInt::zero() {
-                p = p.add(d.clone());
+        if n <= BigInt::zero() || (d.x == BigInt::zero() && d.y == BigInt::zero()) {
+            return Point::zero();
+        }
+
+        let p = Curve::secp256k1().p;
+
+        // Windowed (4-bit) pre-computation: table[w] = w·d.
+        let mut table: Vec<Jacobian> = Vec::with_capacity(16);
+        table.push(Jacobian::infinity());
+        let base = Jacobian::from_affine(&d);
+        for w in 1..16 {
+            let next = table[w - 1].add(&base, &p);
+            table.push(next);
+        }
+
+        let nibbles = n.bits().div_ceil(4).max(1) as usize;
+        let mask = BigInt::from(0xf);
+        let mut acc = Jacobian::infinity();
+        for i in (0..nibbles).rev() {
+            for _ in 0..4 {
+                acc = acc.double(&p);
             }
-            d = d.double();
-            n >>= 1;
+            let w = ((&n >> (4 * i)) & &mask).to_usize().unwrap();
+            acc = acc.add(&table[w], &p);
         }
 
-        p
+        acc.to_affine(&p)
     }
 
     /*
@@ -175,7 +250,7 @@ impl Point {
         let b = m.clone();
         let (_, x, _) = Self::extended_euclid(a, b);
 
-        Self::modulo(x, None)
+        Self::modulo(x, Some(m))
     }
 }
 
@@ -184,3 +259,137 @@ impl AsRef<Point> for Point {
         self
     }
 }
+
+/*
+A point in Jacobian projective coordinates (X, Y, Z), where the affine coordinates are
+x = X/Z² mod p and y = Y/Z³ mod p. The point at infinity is represented by Z = 0.
+Group operations here avoid the modular inversion the affine formulas need on every
+step; a single inversion happens in `to_affine`. secp256k1 has a = 0, which simplifies
+the doubling formula.
+ */
+#[derive(Clone)]
+struct Jacobian {
+    x: BigInt,
+    y: BigInt,
+    z: BigInt,
+}
+
+impl Jacobian {
+    fn infinity() -> Self {
+        Self {
+            x: BigInt::one(),
+            y: BigInt::one(),
+            z: BigInt::zero(),
+        }
+    }
+
+    fn from_affine(point: &Point) -> Self {
+        Self {
+            x: point.x.clone(),
+            y: point.y.clone(),
+            z: BigInt::one(),
+        }
+    }
+
+    fn is_infinity(&self) -> bool {
+        self.z == BigInt::zero()
+    }
+
+    // 2·P — doubling formulas for a = 0.
+    fn double(&self, p: &BigInt) -> Self {
+        if self.is_infinity() || self.y == BigInt::zero() {
+            return Self::infinity();
+        }
+        let m = |n: BigInt| Point::modulo(n, Some(p.clone()));
+        let a = m(&self.x * &self.x);
+        let b = m(&self.y * &self.y);
+        let c = m(&b * &b);
+        let d = m(BigInt::from(2) * (m((&self.x + &b) * (&self.x + &b)) - &a - &c));
+        let e = m(BigInt::from(3) * &a);
+        let f = m(&e * &e);
+        let x3 = m(&f - BigInt::from(2) * &d);
+        let y3 = m(&e * (&d - &x3) - BigInt::from(8) * &c);
+        let z3 = m(BigInt::from(2) * &self.y * &self.z);
+
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    // P + Q.
+    fn add(&self, other: &Self, p: &BigInt) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+        let m = |n: BigInt| Point::modulo(n, Some(p.clone()));
+        let z1z1 = m(&self.z * &self.z);
+        let z2z2 = m(&other.z * &other.z);
+        let u1 = m(&self.x * &z2z2);
+        let u2 = m(&other.x * &z1z1);
+        let s1 = m(&self.y * &other.z * &z2z2);
+        let s2 = m(&other.y * &self.z * &z1z1);
+        let h = m(&u2 - &u1);
+        let r = m(&s2 - &s1);
+        if h == BigInt::zero() {
+            // Same x: either the points are equal (double) or opposite (infinity).
+            return match r == BigInt::zero() {
+                true => self.double(p),
+                false => Self::infinity(),
+            };
+        }
+        let hh = m(&h * &h);
+        let hhh = m(&h * &hh);
+        let u1hh = m(&u1 * &hh);
+        let x3 = m(&r * &r - &hhh - BigInt::from(2) * &u1hh);
+        let y3 = m(&r * (&u1hh - &x3) - &s1 * &hhh);
+        let z3 = m(&self.z * &other.z * &h);
+
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    // Back to affine with a single inversion: x = X·Z⁻² , y = Y·Z⁻³ mod p.
+    fn to_affine(&self, p: &BigInt) -> Point {
+        if self.is_infinity() {
+            return Point::zero();
+        }
+        let z_inv = Point::invert(self.z.clone(), Some(p.clone()));
+        let z_inv2 = Point::modulo(&z_inv * &z_inv, Some(p.clone()));
+        let z_inv3 = Point::modulo(&z_inv2 * &z_inv, Some(p.clone()));
+        let x = Point::modulo(&self.x * &z_inv2, Some(p.clone()));
+        let y = Point::modulo(&self.y * &z_inv3, Some(p.clone()));
+
+        Point::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jacobian_ladder_matches_affine() {
+        let g = Point::secp256k1_base_point();
+
+        // 2G from the windowed ladder must match the affine doubling formula.
+        let two_affine = g.double();
+        let two_ladder = Point::double_and_add(g.clone(), BigInt::from(2));
+        assert_eq!(two_affine.x, two_ladder.x);
+        assert_eq!(two_affine.y, two_ladder.y);
+
+        // 5G built by hand with affine add/double must match the ladder, exercising a
+        // window value other than a power of two from the pre-computed table.
+        let five_affine = g.double().double().add(g.clone());
+        let five_ladder = Point::double_and_add(g.clone(), BigInt::from(5));
+        assert_eq!(five_affine.x, five_ladder.x);
+        assert_eq!(five_affine.y, five_ladder.y);
+    }
+
+    #[test]
+    fn compressed_round_trips_through_decompress() {
+        let point = Point::double_and_add(Point::secp256k1_base_point(), BigInt::from(7));
+        let recovered = Point::decompress(&point.serialize_compressed());
+        assert_eq!(point.x, recovered.x);
+        assert_eq!(point.y, recovered.y);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error;
+
 #[repr(u32)]
 pub enum Version {
     Private = 0x0488ADE4,
@@ -7,6 +9,16 @@ pub enum Version {
 }
 
 impl Version {
+    pub fn from_be_bytes(bytes: [u8; 4]) -> Result<Self, error::Error> {
+        match u32::from_be_bytes(bytes) {
+            0x0488ADE4 => Ok(Version::Private),
+            0x0488B21E => Ok(Version::Public),
+            0x043587CF => Ok(Version::TestnetPublic),
+            0x04358394 => Ok(Version::TestnetPrivate),
+            version => Err(error::Error::UnknownVersion { version }),
+        }
+    }
+
     pub fn to_be_bytes(&self) -> [u8; 4] {
         match self {
             Version::Private => (Version::Private as u32).to_be_bytes(),